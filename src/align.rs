@@ -0,0 +1,178 @@
+//! Needleman-Wunsch alignment, turning two token sequences into two
+//! equal-length sequences with gap markers so they can be displayed
+//! side-by-side with matching regions lined up. This is the approach
+//! delta uses in its `align.rs`.
+
+/// Which edit operation produced a cell in the alignment table, recovered by
+/// following `parent` pointers from the bottom-right cell back to the
+/// top-left origin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    NoOp,
+    Substitution,
+    Insertion,
+    Deletion,
+}
+
+/// A cell in the Needleman-Wunsch alignment table.
+#[derive(Debug, Clone, Copy)]
+struct Cell {
+    cost: usize,
+    parent: usize,
+    operation: Operation,
+}
+
+/// One aligned position. `left` or `right` is `None` exactly where a gap was
+/// inserted on that side, so a deletion shows up as `(Some(_), None)`
+/// and an insertion as `(None, Some(_))`; both sequences come out the same
+/// length, ready to print side by side.
+#[derive(Debug, Clone)]
+pub struct AlignedPair<T> {
+    pub left: Option<T>,
+    pub right: Option<T>,
+}
+
+/// Builds a Needleman-Wunsch alignment table for two token slices and walks
+/// it back from the bottom-right cell to produce a side-by-side alignment:
+/// matching or substituted tokens pair up, a deletion leaves a gap on the
+/// right, and an insertion leaves a gap on the left.
+#[must_use]
+pub fn align<T>(tokens1: &[T], tokens2: &[T]) -> Vec<AlignedPair<T>>
+where
+    T: PartialEq + Clone,
+{
+    let (len1, len2) = (tokens1.len(), tokens2.len());
+    let width = len2 + 1;
+    let index = |i: usize, j: usize| i * width + j;
+
+    let mut table = vec![
+        Cell {
+            cost: 0,
+            parent: 0,
+            operation: Operation::NoOp,
+        };
+        (len1 + 1) * width
+    ];
+
+    for i in 0..=len1 {
+        table[index(i, 0)] = Cell {
+            cost: i,
+            parent: if i == 0 { 0 } else { index(i - 1, 0) },
+            operation: if i == 0 {
+                Operation::NoOp
+            } else {
+                Operation::Deletion
+            },
+        };
+    }
+    for j in 0..=len2 {
+        table[index(0, j)] = Cell {
+            cost: j,
+            parent: if j == 0 { 0 } else { index(0, j - 1) },
+            operation: if j == 0 {
+                Operation::NoOp
+            } else {
+                Operation::Insertion
+            },
+        };
+    }
+
+    for i in 1..=len1 {
+        for j in 1..=len2 {
+            let is_match = tokens1[i - 1] == tokens2[j - 1];
+
+            let diag_cost = table[index(i - 1, j - 1)].cost + usize::from(!is_match);
+            let up_cost = table[index(i - 1, j)].cost + 1;
+            let left_cost = table[index(i, j - 1)].cost + 1;
+
+            let min_cost = diag_cost.min(up_cost).min(left_cost);
+
+            let (parent, operation) = if min_cost == diag_cost {
+                (
+                    index(i - 1, j - 1),
+                    if is_match {
+                        Operation::NoOp
+                    } else {
+                        Operation::Substitution
+                    },
+                )
+            } else if min_cost == up_cost {
+                (index(i - 1, j), Operation::Deletion)
+            } else {
+                (index(i, j - 1), Operation::Insertion)
+            };
+
+            table[index(i, j)] = Cell {
+                cost: min_cost,
+                parent,
+                operation,
+            };
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut i, mut j) = (len1, len2);
+    let mut current = index(i, j);
+
+    while i != 0 || j != 0 {
+        let cell = table[current];
+        match cell.operation {
+            Operation::NoOp | Operation::Substitution => {
+                pairs.push(AlignedPair {
+                    left: Some(tokens1[i - 1].clone()),
+                    right: Some(tokens2[j - 1].clone()),
+                });
+                i -= 1;
+                j -= 1;
+            }
+            Operation::Deletion => {
+                pairs.push(AlignedPair {
+                    left: Some(tokens1[i - 1].clone()),
+                    right: None,
+                });
+                i -= 1;
+            }
+            Operation::Insertion => {
+                pairs.push(AlignedPair {
+                    left: None,
+                    right: Some(tokens2[j - 1].clone()),
+                });
+                j -= 1;
+            }
+        }
+        current = cell.parent;
+    }
+
+    pairs.reverse();
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_align_identical() {
+        let pairs = align(&['a', 'b', 'c'], &['a', 'b', 'c']);
+        assert_eq!(pairs.len(), 3);
+        assert!(pairs
+            .iter()
+            .all(|pair| pair.left == pair.right && pair.left.is_some()));
+    }
+
+    #[test]
+    fn test_align_insertion_leaves_gap_on_left() {
+        let pairs = align(&['a', 'b'], &['a', 'x', 'b']);
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[1].left, None);
+        assert_eq!(pairs[1].right, Some('x'));
+    }
+
+    #[test]
+    fn test_align_deletion_leaves_gap_on_right() {
+        let pairs = align(&['a', 'x', 'b'], &['a', 'b']);
+        assert_eq!(pairs.len(), 3);
+        assert_eq!(pairs[1].left, Some('x'));
+        assert_eq!(pairs[1].right, None);
+    }
+}
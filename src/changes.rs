@@ -1,5 +1,5 @@
-#[derive(Debug, Clone, Copy)]
-/// This enum represents three types of text modifications
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// This enum represents four types of text modifications
 pub enum Change {
     /// This variant represent where a character is inserted at a specified position.
     Insertion(char, usize),
@@ -9,4 +9,25 @@ pub enum Change {
 
     ///Shows where an existing character at a specified position is replaced by another one
     Substitution(char, char, usize),
+
+    /// Shows where two adjacent characters at a specified position (and the
+    /// position right after it) are swapped with each other, e.g. "ab" -> "ba".
+    Transposition(char, char, usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Mirrors [`Change`] at line granularity: each variant carries the full line
+/// text and its line index instead of a single character and position.
+pub enum LineChange {
+    /// This variant represents a line present only in the second file,
+    /// inserted at the specified line index.
+    Insertion(String, usize),
+
+    /// This variant shows a line present only in the first file, deleted
+    /// from the specified line index.
+    Deletion(String, usize),
+
+    /// Shows a line in the first file replaced by a different line in the
+    /// second file, at the specified line index.
+    Substitution(String, String, usize),
 }
@@ -1,6 +1,134 @@
-use crate::changes::Change;
+use crate::changes::{Change, LineChange};
 use anyhow::anyhow;
 
+/// A cell's provenance in the edit-distance table, recorded during the fill
+/// phase so the backtrace doesn't need to re-derive it from costs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Match,
+    Deletion,
+    Insertion,
+    Substitution,
+    Transposition,
+}
+
+/// A single edit produced by [`diff_tokens`], generic over the token type
+/// being compared (`char` for [`levenshtein_diff`], `&str` lines for
+/// [`line_diff`]). Both callers map this into their own public `Change`
+/// representation.
+#[derive(Debug, Clone)]
+enum Edit<T> {
+    Deletion(T, usize),
+    Insertion(T, usize),
+    Substitution(T, T, usize),
+}
+
+/// Computes the Levenshtein edit sequence between two token slices, shared by
+/// the char-level and line-level diff modes. The recurrence and the
+/// direction-matrix backtrace are identical to [`levenshtein_diff`]'s; only
+/// the notion of a "token" changes, so any `T: PartialEq + Clone` can be
+/// compared this way (single `char`s or whole lines).
+///
+/// # Errors
+///
+/// The function returns an error if the difference between the lengths of
+/// tokens1 and tokens2 is greater than usize::MAX.
+fn diff_tokens<T>(tokens1: &[T], tokens2: &[T]) -> anyhow::Result<Vec<Edit<T>>>
+where
+    T: PartialEq + Clone,
+{
+    let (len1, len2) = (tokens1.len(), tokens2.len());
+
+    // Only the previous and current rows of costs are needed to fill the
+    // table, so the matrix is rolled instead of kept in full.
+    let mut prev_row: Vec<usize> = (0..=len2).collect();
+    let mut curr_row = vec![0; len2 + 1];
+
+    // The direction each cell was reached from is kept separately so the
+    // backtrace doesn't need the full cost matrix.
+    let mut directions = vec![vec![Direction::Match; len2 + 1]; len1 + 1];
+    for (j, dir) in directions[0].iter_mut().enumerate() {
+        *dir = if j == 0 {
+            Direction::Match
+        } else {
+            Direction::Insertion
+        };
+    }
+    for row in directions.iter_mut().skip(1) {
+        row[0] = Direction::Deletion;
+    }
+
+    // The matrix is filled with the minimum number of changes required to transform tokens1 into tokens2.
+    // The algorithm is based on the following recurrence relation:
+    // matrix[i][j] = min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1, matrix[i - 1][j - 1] + 1) if tokens1[i] != tokens2[j]
+    // matrix[i][j] = matrix[i - 1][j - 1] if tokens1[i] == tokens2[j]
+    for i in 1..=len1 {
+        curr_row[0] = i;
+
+        for j in 1..=len2 {
+            if tokens1[i - 1] == tokens2[j - 1] {
+                curr_row[j] = prev_row[j - 1];
+                directions[i][j] = Direction::Match;
+            } else {
+                let deletion_cost = prev_row[j];
+                let insertion_cost = curr_row[j - 1];
+                let substitution_cost = prev_row[j - 1];
+
+                curr_row[j] = 1 + deletion_cost.min(insertion_cost).min(substitution_cost);
+
+                directions[i][j] = if deletion_cost < insertion_cost {
+                    Direction::Deletion
+                } else if deletion_cost > insertion_cost {
+                    Direction::Insertion
+                } else if substitution_cost <= deletion_cost {
+                    Direction::Substitution
+                } else {
+                    Direction::Deletion
+                };
+            }
+        }
+
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    let mut edits = Vec::new();
+    let mut i = len1;
+    let mut j = len2;
+
+    // The edits are reconstructed by following the recorded directions from
+    // the bottom right corner of the table back to the top left. Both
+    // indices must be drained to zero, not just one of them, or a leading
+    // run of insertions/deletions down the edge of the table is dropped.
+    while i != 0 || j != 0 {
+        match directions[i][j] {
+            Direction::Match => {
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Deletion => {
+                edits.push(Edit::Deletion(tokens1[i - 1].clone(), i - 1));
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Insertion => {
+                edits.push(Edit::Insertion(tokens2[j - 1].clone(), j - 1));
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Substitution => {
+                edits.push(Edit::Substitution(
+                    tokens1[i - 1].clone(),
+                    tokens2[j - 1].clone(),
+                    i - 1,
+                ));
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Transposition => unreachable!("diff_tokens never records a transposition"),
+        }
+    }
+
+    Ok(edits)
+}
+
 /// Calculates the Levenshtein distance between text1 and text2.
 /// The function takes two string slices as arguments, text1 and text2, and returns
 /// a vector of Change enum variants representing the differences between the two texts.
@@ -15,85 +143,282 @@ use anyhow::anyhow;
 /// The function is safe.
 /// # Performance
 ///
-/// The function has a time complexity of O(nm) and a space complexity of O(nm).
+/// The function has a time complexity of O(nm). The distance-computation phase
+/// only keeps the previous and current rows, each sized by the length of text2
+/// (so O(m) for the rows, whichever input is shorter); backtracking still needs a per-cell record of which operation produced
+/// each cell, so a `Vec<Vec<Direction>>` of that size is kept alongside the rows.
 ///
 /// # See also
 ///
 /// [Wikipedia](https://en.wikipedia.org/wiki/Levenshtein_distance) | [Rosetta Code](https://rosettacode.org/wiki/Levenshtein_distance#Rust) | [Levenshtein Distance](https://www.youtube.com/watch?v=MiqoA-yF-0M) | [Levenshtein Distance](https://www.youtube.com/watch?v=We3YDTzNXEk)
 ///
 pub fn levenshtein_diff(text1: &str, text2: &str) -> anyhow::Result<Vec<Change>> {
-    // The vector of changes is initialized.
-    let mut changes = Vec::new();
-    // The matrix is initialized with the size of the two strings plus one.
-    let mut matrix = vec![vec![0; text2.len() + 1]; text1.len() + 1];
-
-    // The first row and column of the matrix are initialized with the index of the character in the string.
-    matrix
-        .iter_mut()
-        .take(text1.len() + 1)
-        .enumerate()
-        .for_each(|(i, row)| {
-            row[0] = i;
-        });
-
-    matrix[0]
-        .iter_mut()
-        .take(text2.len() + 1)
-        .enumerate()
-        .for_each(|(j, col)| {
-            *col = j;
-        });
-
-    // The matrix is filled with the minimum number of changes required to transform text1 into text2.
-    // The algorithm is based on the following recurrence relation:
-    // matrix[i][j] = min(matrix[i - 1][j] + 1, matrix[i][j - 1] + 1, matrix[i - 1][j - 1] + 1) if text1[i] != text2[j]
-    // matrix[i][j] = matrix[i - 1][j - 1] if text1[i] == text2[j]
+    // Collect both inputs once so the fill and backtrace phases index a
+    // `Vec<char>` in O(1) instead of re-walking the `Chars` iterator each time.
+    let chars1: Vec<char> = text1.chars().collect();
+    let chars2: Vec<char> = text2.chars().collect();
+
+    let edits = diff_tokens(&chars1, &chars2)?;
 
-    for i in 1..=text1.len() {
-        for j in 1..=text2.len() {
-            if text1.chars().nth(i - 1) == text2.chars().nth(j - 1) {
-                matrix[i][j] = matrix[i - 1][j - 1];
+    Ok(edits
+        .into_iter()
+        .map(|edit| match edit {
+            Edit::Deletion(c, pos) => Change::Deletion(c, pos),
+            Edit::Insertion(c, pos) => Change::Insertion(c, pos),
+            Edit::Substitution(c1, c2, pos) => Change::Substitution(c1, c2, pos),
+        })
+        .collect())
+}
+
+/// Calculates a line-oriented diff between text1 and text2: both inputs are
+/// split on `\n` and each line is treated as a single atomic token compared
+/// by equality, reusing the same [`diff_tokens`] core as [`levenshtein_diff`].
+/// This is far more readable than a per-character diff on real source files.
+///
+/// # Errors
+///
+/// The function returns an error if the difference between the number of
+/// lines in text1 and text2 is greater than usize::MAX.
+pub fn line_diff(text1: &str, text2: &str) -> anyhow::Result<Vec<LineChange>> {
+    let lines1: Vec<&str> = text1.split('\n').collect();
+    let lines2: Vec<&str> = text2.split('\n').collect();
+
+    let edits = diff_tokens(&lines1, &lines2)?;
+
+    Ok(edits
+        .into_iter()
+        .map(|edit| match edit {
+            Edit::Deletion(line, idx) => LineChange::Deletion(line.to_string(), idx),
+            Edit::Insertion(line, idx) => LineChange::Insertion(line.to_string(), idx),
+            Edit::Substitution(l1, l2, idx) => {
+                LineChange::Substitution(l1.to_string(), l2.to_string(), idx)
+            }
+        })
+        .collect())
+}
+
+/// Per-operation costs for [`levenshtein_diff_with_costs`], letting a caller
+/// bias the alignment, e.g. make substitution pricier than an insert+delete
+/// pair, or forbid it entirely by giving it a prohibitively high cost.
+#[derive(Debug, Clone, Copy)]
+pub struct Costs {
+    pub insertion: usize,
+    pub deletion: usize,
+    pub substitution: usize,
+}
+
+impl Default for Costs {
+    /// The unit costs used by [`levenshtein_diff`].
+    fn default() -> Self {
+        Self {
+            insertion: 1,
+            deletion: 1,
+            substitution: 1,
+        }
+    }
+}
+
+/// Calculates a weighted Levenshtein diff between text1 and text2 using the
+/// given per-operation [`Costs`] instead of the fixed unit cost used by
+/// [`levenshtein_diff`]. The fill recurrence becomes
+/// `min(up + deletion, left + insertion, diag + (a == b ? 0 : substitution))`,
+/// and the backtrace picks the predecessor consistent with those weights.
+///
+/// # Errors
+///
+/// The function returns an error if the difference between the lengths of
+/// text1 and text2 is greater than usize::MAX.
+pub fn levenshtein_diff_with_costs(
+    text1: &str,
+    text2: &str,
+    costs: Costs,
+) -> anyhow::Result<Vec<Change>> {
+    let chars1: Vec<char> = text1.chars().collect();
+    let chars2: Vec<char> = text2.chars().collect();
+    let (len1, len2) = (chars1.len(), chars2.len());
+
+    let mut prev_row: Vec<usize> = (0..=len2).map(|j| j * costs.insertion).collect();
+    let mut curr_row = vec![0; len2 + 1];
+
+    let mut directions = vec![vec![Direction::Match; len2 + 1]; len1 + 1];
+    for (j, dir) in directions[0].iter_mut().enumerate() {
+        *dir = if j == 0 {
+            Direction::Match
+        } else {
+            Direction::Insertion
+        };
+    }
+    for row in directions.iter_mut().skip(1) {
+        row[0] = Direction::Deletion;
+    }
+
+    for i in 1..=len1 {
+        curr_row[0] = i * costs.deletion;
+
+        for j in 1..=len2 {
+            if chars1[i - 1] == chars2[j - 1] {
+                curr_row[j] = prev_row[j - 1];
+                directions[i][j] = Direction::Match;
+                continue;
+            }
+
+            let deletion_cost = prev_row[j] + costs.deletion;
+            let insertion_cost = curr_row[j - 1] + costs.insertion;
+            let substitution_cost = prev_row[j - 1] + costs.substitution;
+
+            let min_cost = deletion_cost.min(insertion_cost).min(substitution_cost);
+            curr_row[j] = min_cost;
+
+            directions[i][j] = if min_cost == substitution_cost {
+                Direction::Substitution
+            } else if min_cost == deletion_cost {
+                Direction::Deletion
             } else {
-                matrix[i][j] = 1 + std::cmp::min(
-                    matrix[i - 1][j],
-                    std::cmp::min(matrix[i][j - 1], matrix[i - 1][j - 1]),
-                );
+                Direction::Insertion
+            };
+        }
+
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    let mut changes = Vec::new();
+    let mut i = len1;
+    let mut j = len2;
+
+    while i != 0 || j != 0 {
+        match directions[i][j] {
+            Direction::Match => {
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Deletion => {
+                changes.push(Change::Deletion(chars1[i - 1], i - 1));
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Insertion => {
+                changes.push(Change::Insertion(chars2[j - 1], j - 1));
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Substitution => {
+                changes.push(Change::Substitution(chars1[i - 1], chars2[j - 1], i - 1));
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Transposition => {
+                unreachable!("levenshtein_diff_with_costs never records a transposition")
             }
         }
     }
 
-    let mut i = text1.len();
-    let mut j = text2.len();
-
-    // The changes are calculated by traversing the matrix from the bottom right corner to the top left corner.
-    // If the current cell is equal to the cell above it plus one, then a deletion has occurred.
-    // If the current cell is equal to the cell to the left of it plus one, then an insertion has occurred.
-    // If the current cell is equal to the cell to the top left of it plus one, then a substitution has occurred.
-    // If the current cell is equal to the cell to the top left of it, then no change has occurred.
-    while i != 0 && j != 0 {
-        if text1.chars().nth(i - 1) == text2.chars().nth(j - 1) {
-            i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
-            j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
-        } else if matrix[i - 1][j] < matrix[i][j - 1] {
-            changes.push(Change::Deletion(
-                text1.chars().nth(i - 1).ok_or(anyhow!("Underflow Error"))?,
-                i - 1,
-            ));
-            i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
-        } else if matrix[i - 1][j] > matrix[i][j - 1] {
-            changes.push(Change::Insertion(
-                text2.chars().nth(j - 1).ok_or(anyhow!("Underflow Error"))?,
-                j - 1,
-            ));
-            j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+    Ok(changes)
+}
+
+/// Calculates the optimal-string-alignment Damerau-Levenshtein distance
+/// between text1 and text2, which extends [`levenshtein_diff`] with a single
+/// extra edit operation: transposing two adjacent characters. This correctly
+/// and compactly describes a reordering such as "ab" -> "ba" as one
+/// [`Change::Transposition`] instead of two substitutions.
+///
+/// # Errors
+///
+/// The function returns an error if the difference between the lengths of
+/// text1 and text2 is greater than usize::MAX.
+///
+/// # Performance
+///
+/// Same O(nm) time as [`levenshtein_diff`]. The transposition check needs the
+/// row from two steps back, so three rows are rolled instead of two; the
+/// backtrace still relies on the compact per-cell `Direction` record.
+pub fn damerau_diff(text1: &str, text2: &str) -> anyhow::Result<Vec<Change>> {
+    let chars1: Vec<char> = text1.chars().collect();
+    let chars2: Vec<char> = text2.chars().collect();
+    let (len1, len2) = (chars1.len(), chars2.len());
+
+    let mut prev_prev_row = vec![0; len2 + 1];
+    let mut prev_row: Vec<usize> = (0..=len2).collect();
+    let mut curr_row = vec![0; len2 + 1];
+
+    let mut directions = vec![vec![Direction::Match; len2 + 1]; len1 + 1];
+    for (j, dir) in directions[0].iter_mut().enumerate() {
+        *dir = if j == 0 {
+            Direction::Match
         } else {
-            changes.push(Change::Substitution(
-                text1.chars().nth(i - 1).ok_or(anyhow!("Underflow Error"))?,
-                text2.chars().nth(j - 1).ok_or(anyhow!("Underflow Error"))?,
-                i - 1,
-            ));
-            i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
-            j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            Direction::Insertion
+        };
+    }
+    for row in directions.iter_mut().skip(1) {
+        row[0] = Direction::Deletion;
+    }
+
+    for i in 1..=len1 {
+        curr_row[0] = i;
+
+        for j in 1..=len2 {
+            if chars1[i - 1] == chars2[j - 1] {
+                curr_row[j] = prev_row[j - 1];
+                directions[i][j] = Direction::Match;
+                continue;
+            }
+
+            let deletion_cost = prev_row[j];
+            let insertion_cost = curr_row[j - 1];
+            let substitution_cost = prev_row[j - 1];
+
+            let mut cost = 1 + deletion_cost.min(insertion_cost).min(substitution_cost);
+            let mut direction = if deletion_cost < insertion_cost {
+                Direction::Deletion
+            } else if deletion_cost > insertion_cost {
+                Direction::Insertion
+            } else if substitution_cost <= deletion_cost {
+                Direction::Substitution
+            } else {
+                Direction::Deletion
+            };
+
+            if i > 1 && j > 1 && chars1[i - 1] == chars2[j - 2] && chars1[i - 2] == chars2[j - 1] {
+                let transposition_cost = prev_prev_row[j - 2] + 1;
+                if transposition_cost < cost {
+                    cost = transposition_cost;
+                    direction = Direction::Transposition;
+                }
+            }
+
+            curr_row[j] = cost;
+            directions[i][j] = direction;
+        }
+
+        std::mem::swap(&mut prev_prev_row, &mut prev_row);
+        std::mem::swap(&mut prev_row, &mut curr_row);
+    }
+
+    let mut changes = Vec::new();
+    let mut i = len1;
+    let mut j = len2;
+
+    while i != 0 || j != 0 {
+        match directions[i][j] {
+            Direction::Match => {
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Deletion => {
+                changes.push(Change::Deletion(chars1[i - 1], i - 1));
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Insertion => {
+                changes.push(Change::Insertion(chars2[j - 1], j - 1));
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Substitution => {
+                changes.push(Change::Substitution(chars1[i - 1], chars2[j - 1], i - 1));
+                i = i.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+                j = j.checked_sub(1).ok_or(anyhow!("Underflow Error"))?;
+            }
+            Direction::Transposition => {
+                changes.push(Change::Transposition(chars1[i - 2], chars1[i - 1], i - 2));
+                i -= 2;
+                j -= 2;
+            }
         }
     }
 
@@ -167,4 +492,67 @@ mod tests {
     }
 
     // Add more tests
+
+    #[test]
+    fn test_damerau_transposition() {
+        let changes = damerau_diff("ab", "ba").unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0], Change::Transposition('a', 'b', 0));
+    }
+
+    #[test]
+    fn test_damerau_falls_back_to_levenshtein() {
+        let changes = damerau_diff("abcd", "abed").unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0], Change::Substitution('c', 'e', 2));
+    }
+
+    #[test]
+    fn test_damerau_same_string() {
+        let changes = damerau_diff("abcd", "abcd").unwrap();
+        assert_eq!(changes.len(), 0);
+    }
+
+    #[test]
+    fn test_line_diff_same_text() {
+        let changes = line_diff("a\nb\nc", "a\nb\nc").unwrap();
+        assert_eq!(changes.len(), 0);
+    }
+
+    #[test]
+    fn test_line_diff_substitution() {
+        let changes = line_diff("a\nb\nc", "a\nx\nc").unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(
+            changes[0],
+            LineChange::Substitution("b".to_string(), "x".to_string(), 1)
+        );
+    }
+
+    #[test]
+    fn test_line_diff_insertion() {
+        let changes = line_diff("a\nb", "a\nb\nc").unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0], LineChange::Insertion("c".to_string(), 2));
+    }
+
+    #[test]
+    fn test_with_costs_defaults_match_levenshtein_diff() {
+        let changes = levenshtein_diff_with_costs("abcd", "abed", Costs::default()).unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0], Change::Substitution('c', 'e', 2));
+    }
+
+    #[test]
+    fn test_with_costs_forbids_substitution() {
+        let costs = Costs {
+            insertion: 1,
+            deletion: 1,
+            substitution: 100,
+        };
+        let changes = levenshtein_diff_with_costs("abcd", "abed", costs).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0], Change::Deletion('c', 2));
+        assert_eq!(changes[1], Change::Insertion('e', 2));
+    }
 }
@@ -0,0 +1,9 @@
+//! `riff` is a small Levenshtein/Damerau-Levenshtein diffing library that
+//! also ships as a CLI. The [`levenshtein`] module exposes the diff API
+//! (enumerating the edits between two texts), while [`metrics`] exposes
+//! normalized similarity scores for callers that want to rank or fuzzy-match
+//! strings instead.
+
+pub mod changes;
+pub mod levenshtein;
+pub mod metrics;
@@ -1,28 +1,233 @@
-mod changes;
-mod levenshtein;
+mod align;
+mod unified;
 use std::path::Path;
+use std::str::FromStr;
 
 use anyhow::anyhow;
-use changes::Change;
 use colored::Colorize;
-use levenshtein::levenshtein_diff;
+use riff::changes::{Change, LineChange};
+use riff::levenshtein::{damerau_diff, levenshtein_diff_with_costs, line_diff, Costs};
+
+/// The number of context lines kept around a change in `--format unified` output.
+const UNIFIED_CONTEXT_LINES: usize = 3;
+
+/// Which granularity `riff` compares the two files at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// Per-character diffing via [`damerau_diff`].
+    Char,
+
+    /// Per-line diffing via [`line_diff`].
+    Line,
+}
+
+impl FromStr for Mode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "char" => Ok(Mode::Char),
+            "line" => Ok(Mode::Line),
+            other => Err(anyhow!("Unknown mode '{}', expected 'char' or 'line'", other)),
+        }
+    }
+}
+
+/// How `riff` presents the computed diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// The colored one-change-per-line view (default).
+    Changes,
+
+    /// A standard unified diff, always computed line-by-line regardless of `--mode`.
+    Unified,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "changes" => Ok(Format::Changes),
+            "unified" => Ok(Format::Unified),
+            other => Err(anyhow!(
+                "Unknown format '{}', expected 'changes' or 'unified'",
+                other
+            )),
+        }
+    }
+}
 
 fn main() -> anyhow::Result<()> {
     let args: Vec<_> = std::env::args().collect();
 
-    if args.len() > 3 {
-        return Err(anyhow!("Usage: {} <file> <file>", args[0]));
+    let mut mode = Mode::Char;
+    let mut format = Format::Changes;
+    let mut cost_ins = None;
+    let mut cost_del = None;
+    let mut cost_sub = None;
+    let mut side_by_side = false;
+    let mut files = Vec::new();
+
+    let mut rest = args.iter().skip(1);
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--mode" => {
+                let value = rest.next().ok_or_else(|| anyhow!("--mode requires a value"))?;
+                mode = value.parse()?;
+            }
+            "--format" => {
+                let value = rest
+                    .next()
+                    .ok_or_else(|| anyhow!("--format requires a value"))?;
+                format = value.parse()?;
+            }
+            "--cost-ins" => cost_ins = Some(parse_cost("--cost-ins", &mut rest)?),
+            "--cost-del" => cost_del = Some(parse_cost("--cost-del", &mut rest)?),
+            "--cost-sub" => cost_sub = Some(parse_cost("--cost-sub", &mut rest)?),
+            "--side-by-side" => side_by_side = true,
+            other => files.push(other),
+        }
+    }
+
+    if files.len() != 2 {
+        return Err(anyhow!(
+            "Usage: {} <file> <file> [--mode char|line] [--format changes|unified] [--cost-ins/--cost-del/--cost-sub N] [--side-by-side]",
+            args[0]
+        ));
+    }
+
+    let file_one = read_file(Path::new(&files[0]))?;
+    let file_two = read_file(Path::new(&files[1]))?;
+
+    let costs = if cost_ins.is_some() || cost_del.is_some() || cost_sub.is_some() {
+        Some(Costs {
+            insertion: cost_ins.unwrap_or(1),
+            deletion: cost_del.unwrap_or(1),
+            substitution: cost_sub.unwrap_or(1),
+        })
+    } else {
+        None
+    };
+
+    if side_by_side {
+        print_side_by_side(&file_one, &file_two, mode);
+        return Ok(());
+    }
+
+    match format {
+        Format::Unified => print_unified_diff(files[0], files[1], &file_one, &file_two)?,
+        Format::Changes => match mode {
+            Mode::Char => print_char_diff(&args[0], &file_one, &file_two, costs)?,
+            Mode::Line => print_line_diff(&args[0], &file_one, &file_two)?,
+        },
+    }
+
+    Ok(())
+}
+
+/// Prints the two files as a side-by-side alignment built from
+/// [`align::align`], at the granularity selected by `--mode`: whole lines for
+/// [`Mode::Line`], single characters for [`Mode::Char`]. Matching regions
+/// line up; a differing pair is highlighted, and a gap marks a pure
+/// insertion or deletion on the other side.
+fn print_side_by_side(file_one: &str, file_two: &str, mode: Mode) {
+    match mode {
+        Mode::Char => {
+            let chars1: Vec<char> = file_one.chars().collect();
+            let chars2: Vec<char> = file_two.chars().collect();
+
+            for pair in align::align(&chars1, &chars2) {
+                print_aligned_row(pair.left.map(String::from), pair.right.map(String::from));
+            }
+        }
+        Mode::Line => {
+            let lines1: Vec<&str> = file_one.split('\n').collect();
+            let lines2: Vec<&str> = file_two.split('\n').collect();
+
+            for pair in align::align(&lines1, &lines2) {
+                print_aligned_row(
+                    pair.left.map(str::to_string),
+                    pair.right.map(str::to_string),
+                );
+            }
+        }
+    }
+}
+
+/// Prints one row of a side-by-side alignment, coloring it by whether the
+/// two sides match, differ, or one side is a gap.
+fn print_aligned_row(left: Option<String>, right: Option<String>) {
+    const GAP: &str = "";
+
+    match (left, right) {
+        (Some(l), Some(r)) if l == r => println!("{l} | {r}"),
+        (Some(l), Some(r)) => println!("{} | {}", l.yellow(), r.yellow()),
+        (Some(l), None) => println!("{} | {}", l.red(), GAP),
+        (None, Some(r)) => println!("{} | {}", GAP, r.green()),
+        (None, None) => unreachable!("align never produces a gap on both sides"),
     }
+}
+
+/// Parses the value following a `--cost-*` flag as a `usize`.
+fn parse_cost<'a>(
+    flag: &str,
+    rest: &mut impl Iterator<Item = &'a String>,
+) -> anyhow::Result<usize> {
+    let value = rest
+        .next()
+        .ok_or_else(|| anyhow!("{} requires a value", flag))?;
+    value
+        .parse()
+        .map_err(|_| anyhow!("{} expects a non-negative integer, got '{}'", flag, value))
+}
+
+/// Runs the line-level diff and prints it as a unified diff, the format
+/// `patch` and most code-review tooling already understands.
+fn print_unified_diff(
+    file_one_name: &str,
+    file_two_name: &str,
+    file_one: &str,
+    file_two: &str,
+) -> anyhow::Result<()> {
+    let lines1: Vec<&str> = file_one.split('\n').collect();
+    let lines2: Vec<&str> = file_two.split('\n').collect();
+    let changes = line_diff(file_one, file_two)?;
 
-    let file_one = read_file(Path::new(&args[1]))?;
-    let file_two = read_file(Path::new(&args[2]))?;
+    print!(
+        "{}",
+        unified::format_unified_diff(
+            file_one_name,
+            file_two_name,
+            &lines1,
+            &lines2,
+            &changes,
+            UNIFIED_CONTEXT_LINES,
+        )
+    );
 
-    let changes = levenshtein_diff(&file_one, &file_two)?;
+    Ok(())
+}
+
+/// Runs the character-level diff and prints each [`Change`] in color. When
+/// `costs` is given, a weighted [`levenshtein_diff_with_costs`] is used
+/// instead of the default [`damerau_diff`], which always uses unit costs and
+/// also reports transpositions.
+fn print_char_diff(
+    program: &str,
+    file_one: &str,
+    file_two: &str,
+    costs: Option<Costs>,
+) -> anyhow::Result<()> {
+    let changes = match costs {
+        Some(costs) => levenshtein_diff_with_costs(file_one, file_two, costs)?,
+        None => damerau_diff(file_one, file_two)?,
+    };
 
     if changes.is_empty() {
         println!(
             "{} {}",
-            args[0].red(),
+            program.red(),
             format!("{} {}", "Files are identical".green(), "✓",).green(),
         );
     }
@@ -51,6 +256,46 @@ fn main() -> anyhow::Result<()> {
                     format!("'{}' with '{}' at position {}", c1, c2, pos).yellow()
                 );
             }
+            Change::Transposition(c1, c2, pos) => {
+                println!(
+                    "{} {}",
+                    "Transposition".magenta(),
+                    format!("'{}' and '{}' at position {}", c1, c2, pos).magenta()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs the line-level diff and prints each [`LineChange`] in color.
+fn print_line_diff(program: &str, file_one: &str, file_two: &str) -> anyhow::Result<()> {
+    let changes = line_diff(file_one, file_two)?;
+
+    if changes.is_empty() {
+        println!(
+            "{} {}",
+            program.red(),
+            format!("{} {}", "Files are identical".green(), "✓",).green(),
+        );
+    }
+
+    for change in changes {
+        match change {
+            LineChange::Insertion(line, idx) => {
+                println!("{} {}", "+".green(), format!("{}: {}", idx, line).green());
+            }
+            LineChange::Deletion(line, idx) => {
+                println!("{} {}", "-".red(), format!("{}: {}", idx, line).red());
+            }
+            LineChange::Substitution(l1, l2, idx) => {
+                println!(
+                    "{} {}",
+                    "~".yellow(),
+                    format!("{}: '{}' with '{}'", idx, l1, l2).yellow()
+                );
+            }
         }
     }
 
@@ -0,0 +1,192 @@
+//! Normalized string-similarity scores, mirroring the metrics offered by the
+//! `strsim` crate. Where [`crate::levenshtein`] enumerates the edits between
+//! two texts, this module collapses that information into a single score
+//! for ranking or fuzzy-matching strings.
+
+use anyhow::{anyhow, Result};
+
+use crate::levenshtein::levenshtein_diff;
+
+/// Counts the positions at which two equal-length strings differ.
+///
+/// # Errors
+///
+/// Returns an error if `a` and `b` have different lengths.
+pub fn hamming(a: &str, b: &str) -> Result<usize> {
+    let chars_a: Vec<char> = a.chars().collect();
+    let chars_b: Vec<char> = b.chars().collect();
+
+    if chars_a.len() != chars_b.len() {
+        return Err(anyhow!(
+            "Hamming distance requires equal-length strings, got {} and {}",
+            chars_a.len(),
+            chars_b.len()
+        ));
+    }
+
+    Ok(chars_a
+        .iter()
+        .zip(chars_b.iter())
+        .filter(|(x, y)| x != y)
+        .count())
+}
+
+/// Computes the Jaro similarity between `a` and `b`, a value in `[0.0, 1.0]`
+/// where `1.0` means identical and `0.0` means no characters matched.
+#[must_use]
+pub fn jaro(a: &str, b: &str) -> f64 {
+    let chars_a: Vec<char> = a.chars().collect();
+    let chars_b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (chars_a.len(), chars_b.len());
+
+    if len_a == 0 && len_b == 0 {
+        return 1.0;
+    }
+    if len_a == 0 || len_b == 0 {
+        return 0.0;
+    }
+
+    let match_distance = (len_a.max(len_b) / 2).saturating_sub(1);
+
+    let mut a_matched = vec![false; len_a];
+    let mut b_matched = vec![false; len_b];
+    let mut matches = 0usize;
+
+    for i in 0..len_a {
+        let start = i.saturating_sub(match_distance);
+        let end = (i + match_distance + 1).min(len_b);
+
+        for (j, matched) in b_matched.iter_mut().enumerate().take(end).skip(start) {
+            if *matched || chars_a[i] != chars_b[j] {
+                continue;
+            }
+
+            a_matched[i] = true;
+            *matched = true;
+            matches += 1;
+            break;
+        }
+    }
+
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0usize;
+    let mut b_index = 0;
+    for (i, &is_match) in a_matched.iter().enumerate() {
+        if !is_match {
+            continue;
+        }
+        while !b_matched[b_index] {
+            b_index += 1;
+        }
+        if chars_a[i] != chars_b[b_index] {
+            transpositions += 1;
+        }
+        b_index += 1;
+    }
+    let transpositions = transpositions / 2;
+
+    let m = matches as f64;
+    (m / len_a as f64 + m / len_b as f64 + (m - transpositions as f64) / m) / 3.0
+}
+
+/// Common-prefix length considered by [`jaro_winkler`], capped so a long
+/// shared prefix doesn't dominate the score.
+const JARO_WINKLER_MAX_PREFIX: usize = 4;
+
+/// Weight given to each common-prefix character in [`jaro_winkler`].
+const JARO_WINKLER_PREFIX_SCALE: f64 = 0.1;
+
+/// Computes the Jaro-Winkler similarity between `a` and `b`: the [`jaro`]
+/// score boosted by a shared prefix of up to [`JARO_WINKLER_MAX_PREFIX`]
+/// characters, weighted by [`JARO_WINKLER_PREFIX_SCALE`].
+#[must_use]
+pub fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let jaro_score = jaro(a, b);
+
+    let prefix_len = a
+        .chars()
+        .zip(b.chars())
+        .take(JARO_WINKLER_MAX_PREFIX)
+        .take_while(|(x, y)| x == y)
+        .count();
+
+    jaro_score + prefix_len as f64 * JARO_WINKLER_PREFIX_SCALE * (1.0 - jaro_score)
+}
+
+/// Returns a similarity score in `[0.0, 1.0]` derived from the Levenshtein
+/// distance between `a` and `b`: `1.0` for identical strings, decreasing
+/// toward `0.0` as the distance approaches the longer string's length.
+///
+/// # Errors
+///
+/// Propagates any error from [`levenshtein_diff`].
+pub fn normalized_levenshtein(a: &str, b: &str) -> Result<f64> {
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return Ok(1.0);
+    }
+
+    let distance = levenshtein_diff(a, b)?.len();
+    Ok(1.0 - (distance as f64 / max_len as f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hamming_equal_strings() {
+        assert_eq!(hamming("karolin", "karolin").unwrap(), 0);
+    }
+
+    #[test]
+    fn test_hamming_mismatch_count() {
+        assert_eq!(hamming("karolin", "kathrin").unwrap(), 3);
+    }
+
+    #[test]
+    fn test_hamming_rejects_unequal_lengths() {
+        assert!(hamming("abc", "ab").is_err());
+    }
+
+    #[test]
+    fn test_jaro_identical_strings() {
+        assert!((jaro("hello", "hello") - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_jaro_empty_strings() {
+        assert!((jaro("", "") - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_jaro_no_match() {
+        assert_eq!(jaro("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn test_jaro_winkler_boosts_common_prefix() {
+        let jaro_score = jaro("martha", "marhta");
+        let jaro_winkler_score = jaro_winkler("martha", "marhta");
+        assert!(jaro_winkler_score >= jaro_score);
+    }
+
+    #[test]
+    fn test_normalized_levenshtein_identical() {
+        assert!((normalized_levenshtein("abc", "abc").unwrap() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_normalized_levenshtein_empty_strings() {
+        assert!((normalized_levenshtein("", "").unwrap() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_normalized_levenshtein_leading_deletion() {
+        let score = normalized_levenshtein("abc", "bc").unwrap();
+        assert!((score - 2.0 / 3.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,170 @@
+use riff::changes::LineChange;
+
+/// One line of unified-diff output, tagged with its role.
+#[derive(Debug, Clone)]
+enum HunkLine {
+    Context(String),
+    Added(String),
+    Removed(String),
+}
+
+/// A contiguous run of context and changed lines, with the old/new start
+/// line numbers and lengths needed to render an `@@ -start,len +start,len @@`
+/// header the way `diff -u` does.
+#[derive(Debug, Clone)]
+struct Hunk {
+    old_start: usize,
+    old_len: usize,
+    new_start: usize,
+    new_len: usize,
+    lines: Vec<HunkLine>,
+}
+
+/// Replays a line-level change list against the two original files to
+/// recover the full line-by-line alignment, including the matching lines
+/// that [`LineChange`] doesn't carry. `changes` must be in ascending file
+/// order (oldest change first).
+fn align<'a>(
+    lines1: &[&'a str],
+    lines2: &[&'a str],
+    changes: &[LineChange],
+) -> Vec<(HunkLine, Option<usize>, Option<usize>)> {
+    let mut entries = Vec::new();
+    let mut changes = changes.iter().peekable();
+    let (mut i, mut j) = (0, 0);
+
+    while i < lines1.len() || j < lines2.len() {
+        match changes.peek() {
+            Some(LineChange::Deletion(_, idx)) if *idx == i => {
+                entries.push((HunkLine::Removed(lines1[i].to_string()), Some(i + 1), None));
+                changes.next();
+                i += 1;
+            }
+            Some(LineChange::Insertion(_, idx)) if *idx == j => {
+                entries.push((HunkLine::Added(lines2[j].to_string()), None, Some(j + 1)));
+                changes.next();
+                j += 1;
+            }
+            Some(LineChange::Substitution(_, _, idx)) if *idx == i => {
+                entries.push((HunkLine::Removed(lines1[i].to_string()), Some(i + 1), None));
+                entries.push((HunkLine::Added(lines2[j].to_string()), None, Some(j + 1)));
+                changes.next();
+                i += 1;
+                j += 1;
+            }
+            _ => {
+                assert_eq!(
+                    lines1[i], lines2[j],
+                    "line_diff under-reported a change at old line {}, new line {}",
+                    i + 1,
+                    j + 1
+                );
+                entries.push((
+                    HunkLine::Context(lines1[i].to_string()),
+                    Some(i + 1),
+                    Some(j + 1),
+                ));
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+
+    entries
+}
+
+/// Groups the aligned lines into hunks, coalescing changes whose `context`-line
+/// windows overlap (at most `2 * context` entries apart) into a single hunk.
+fn build_hunks(lines1: &[&str], lines2: &[&str], changes: &[LineChange], context: usize) -> Vec<Hunk> {
+    let entries = align(lines1, lines2, changes);
+
+    let changed_indices: Vec<usize> = entries
+        .iter()
+        .enumerate()
+        .filter(|(_, (line, _, _))| !matches!(line, HunkLine::Context(_)))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    if changed_indices.is_empty() {
+        return Vec::new();
+    }
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let (mut start, mut end) = (changed_indices[0], changed_indices[0]);
+    for &idx in &changed_indices[1..] {
+        if idx - end <= context * 2 {
+            end = idx;
+        } else {
+            ranges.push((start, end));
+            start = idx;
+            end = idx;
+        }
+    }
+    ranges.push((start, end));
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            let from = start.saturating_sub(context);
+            let to = (end + context).min(entries.len() - 1);
+            let slice = &entries[from..=to];
+
+            let old_start = slice.iter().find_map(|(_, old, _)| *old).unwrap_or(1);
+            let new_start = slice.iter().find_map(|(_, _, new)| *new).unwrap_or(1);
+            let old_len = slice
+                .iter()
+                .filter(|(line, _, _)| !matches!(line, HunkLine::Added(_)))
+                .count();
+            let new_len = slice
+                .iter()
+                .filter(|(line, _, _)| !matches!(line, HunkLine::Removed(_)))
+                .count();
+
+            Hunk {
+                old_start,
+                old_len,
+                new_start,
+                new_len,
+                lines: slice.iter().map(|(line, ..)| line.clone()).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Renders a unified diff (`--- a/file`, `+++ b/file`, `@@ ... @@` hunks) for
+/// the two files, built on top of the line-level change list returned by
+/// [`riff::levenshtein::line_diff`].
+///
+/// `changes` is reversed internally since `line_diff` returns changes in
+/// reverse file order (last line first), matching the rest of the diffing
+/// core.
+pub fn format_unified_diff(
+    file_one: &str,
+    file_two: &str,
+    lines1: &[&str],
+    lines2: &[&str],
+    changes: &[LineChange],
+    context: usize,
+) -> String {
+    let ordered: Vec<LineChange> = changes.iter().rev().cloned().collect();
+    let hunks = build_hunks(lines1, lines2, &ordered, context);
+
+    let mut output = format!("--- a/{file_one}\n+++ b/{file_two}\n");
+
+    for hunk in hunks {
+        output.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            hunk.old_start, hunk.old_len, hunk.new_start, hunk.new_len
+        ));
+
+        for line in hunk.lines {
+            match line {
+                HunkLine::Context(text) => output.push_str(&format!(" {text}\n")),
+                HunkLine::Added(text) => output.push_str(&format!("+{text}\n")),
+                HunkLine::Removed(text) => output.push_str(&format!("-{text}\n")),
+            }
+        }
+    }
+
+    output
+}